@@ -0,0 +1,42 @@
+// Copyright 2015-2020 Aerospike, Inc.
+//
+// Portions may be licensed to Aerospike, Inc. under one or more contributor
+// license agreements.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use crate::errors::Error;
+use crate::Key;
+
+/// Summary of a bulk write operation.
+#[derive(Debug, Default)]
+pub struct BulkWriteSummary {
+    /// Number of records written successfully.
+    pub written: usize,
+
+    /// Records that could not be written, together with the error returned for each of them.
+    pub failures: Vec<(Key, Error)>,
+
+    /// Whether the operation stopped early because a write failed and the policy requested to
+    /// abort on error. Records remaining in the input have not been read.
+    pub aborted: bool,
+
+    /// Records that had already been read from the input when the operation was aborted, but
+    /// were not written.
+    pub skipped: Vec<Key>,
+}
+
+impl BulkWriteSummary {
+    /// Returns `true` if all records were written successfully.
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright 2015-2020 Aerospike, Inc.
+//
+// Portions may be licensed to Aerospike, Inc. under one or more contributor
+// license agreements.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use parking_lot::{Condvar, Mutex};
+use scoped_pool::Pool;
+
+use crate::batch::BulkWriteSummary;
+use crate::cluster::partition::Partition;
+use crate::cluster::{Cluster, Node};
+use crate::commands::WriteCommand;
+use crate::errors::{ErrorKind, Result};
+use crate::operations::OperationType;
+use crate::policy::BulkWritePolicy;
+use crate::{Bin, Key};
+
+pub struct BulkWriteExecutor {
+    cluster: Arc<Cluster>,
+    thread_pool: Pool,
+}
+
+impl BulkWriteExecutor {
+    pub fn new(cluster: Arc<Cluster>, thread_pool: Pool) -> Self {
+        BulkWriteExecutor {
+            cluster,
+            thread_pool,
+        }
+    }
+
+    pub fn execute_put<'b, I>(
+        &self,
+        policy: &BulkWritePolicy,
+        records: I,
+    ) -> Result<BulkWriteSummary>
+    where
+        I: IntoIterator<Item = (Key, Vec<Bin<'b>>)>,
+    {
+        if policy.max_in_flight == 0 || policy.max_in_flight_per_node == 0 {
+            bail!(ErrorKind::InvalidArgument(
+                "Max. number of write requests in flight must be greater than zero".to_string()
+            ));
+        }
+
+        let in_flight = InFlight::new(policy.max_in_flight, policy.max_in_flight_per_node);
+        let summary: Mutex<BulkWriteSummary> = Mutex::default();
+        let failed = || policy.abort_on_error && !summary.lock().is_success();
+        let mut records = records.into_iter();
+        self.thread_pool.scoped(|scope| {
+            loop {
+                // do not take any further records from the input once a write failed
+                if failed() {
+                    summary.lock().aborted = true;
+                    break;
+                }
+
+                let (key, bins) = match records.next() {
+                    Some(record) => record,
+                    None => break,
+                };
+
+                let node = match self.node_for_key(&key) {
+                    Ok(node) => node,
+                    Err(err) => {
+                        summary.lock().failures.push((key, err));
+                        continue;
+                    }
+                };
+
+                // blocks until there is room for another request to this node
+                let slot = in_flight.acquire(node);
+
+                // a pending write may have failed while waiting for the slot
+                if failed() {
+                    let mut summary = summary.lock();
+                    summary.aborted = true;
+                    summary.skipped.push(key);
+                    break;
+                }
+
+                let cluster = self.cluster.clone();
+                let summary = &summary;
+                scope.execute(move || {
+                    // the slot is released when the job ends, even if it panics
+                    let _slot = slot;
+                    let result = WriteCommand::new(
+                        &policy.write_policy,
+                        cluster,
+                        &key,
+                        &bins,
+                        OperationType::Write,
+                    )
+                    .execute();
+
+                    match result {
+                        Ok(()) => summary.lock().written += 1,
+                        Err(err) => summary.lock().failures.push((key, err)),
+                    }
+                });
+            }
+        });
+        Ok(summary.into_inner())
+    }
+
+    fn node_for_key(&self, key: &Key) -> Result<Arc<Node>> {
+        let partition = Partition::new_by_key(key);
+        let node = self.cluster.get_node(&partition)?;
+        Ok(node)
+    }
+}
+
+// Number of write requests in flight, in total and per cluster node.
+struct InFlightCounts<N> {
+    total: usize,
+    per_node: HashMap<N, usize>,
+}
+
+// Bounds the number of write requests in flight. Producers block in `acquire` until the request
+// fits within both the total and the per-node limit.
+struct InFlight<N> {
+    max_total: usize,
+    max_per_node: usize,
+    counts: Mutex<InFlightCounts<N>>,
+    released: Condvar,
+}
+
+impl<N: Hash + Eq + Clone> InFlight<N> {
+    fn new(max_total: usize, max_per_node: usize) -> Self {
+        InFlight {
+            max_total,
+            max_per_node,
+            counts: Mutex::new(InFlightCounts {
+                total: 0,
+                per_node: HashMap::new(),
+            }),
+            released: Condvar::new(),
+        }
+    }
+
+    fn acquire(&self, node: N) -> InFlightSlot<'_, N> {
+        let mut counts = self.counts.lock();
+        while counts.total >= self.max_total
+            || counts
+                .per_node
+                .get(&node)
+                .map_or(false, |&count| count >= self.max_per_node)
+        {
+            self.released.wait(&mut counts);
+        }
+
+        counts.total += 1;
+        *counts.per_node.entry(node.clone()).or_insert(0) += 1;
+
+        InFlightSlot {
+            in_flight: self,
+            node,
+        }
+    }
+
+    fn release(&self, node: &N) {
+        let mut counts = self.counts.lock();
+        counts.total -= 1;
+        if let Some(count) = counts.per_node.get_mut(node) {
+            *count -= 1;
+        }
+        self.released.notify_all();
+    }
+}
+
+// A request slot acquired from `InFlight`; the slot is released when dropped.
+struct InFlightSlot<'a, N: Hash + Eq + Clone> {
+    in_flight: &'a InFlight<N>,
+    node: N,
+}
+
+impl<'a, N: Hash + Eq + Clone> Drop for InFlightSlot<'a, N> {
+    fn drop(&mut self) {
+        self.in_flight.release(&self.node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use scoped_pool::Pool;
+
+    use super::InFlight;
+
+    // Returns true if acquiring a slot for `node` blocks until `release` is called.
+    fn blocks_until<F>(in_flight: &InFlight<&'static str>, node: &'static str, release: F) -> bool
+    where
+        F: FnOnce(),
+    {
+        let (tx, rx) = mpsc::channel();
+        let pool = Pool::new(1);
+        let unblocked = pool.scoped(|scope| {
+            scope.execute(move || {
+                let _slot = in_flight.acquire(node);
+                tx.send(()).unwrap();
+            });
+
+            let blocked = rx.recv_timeout(Duration::from_millis(100)).is_err();
+            release();
+            blocked && rx.recv_timeout(Duration::from_secs(5)).is_ok()
+        });
+        pool.shutdown();
+        unblocked
+    }
+
+    #[test]
+    fn total_limit() {
+        let in_flight = InFlight::new(2, 2);
+        let a = in_flight.acquire("a");
+        let _b = in_flight.acquire("b");
+
+        assert!(blocks_until(&in_flight, "c", || drop(a)));
+    }
+
+    #[test]
+    fn per_node_limit() {
+        let in_flight = InFlight::new(10, 2);
+        let a1 = in_flight.acquire("a");
+        let _a2 = in_flight.acquire("a");
+
+        // other nodes are not affected by the limit on node "a"
+        let _b = in_flight.acquire("b");
+
+        assert!(blocks_until(&in_flight, "a", || drop(a1)));
+    }
+
+    #[test]
+    fn slot_released_on_panic() {
+        let in_flight = InFlight::new(1, 1);
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let _slot = in_flight.acquire("a");
+            panic!("write failed");
+        }));
+        assert!(result.is_err());
+
+        let counts = in_flight.counts.lock();
+        assert_eq!(counts.total, 0);
+        assert_eq!(counts.per_node.get("a"), Some(&0));
+    }
+}
@@ -15,6 +15,10 @@
 
 pub mod batch_executor;
 pub mod batch_read;
+pub mod bulk_write;
+pub mod bulk_write_executor;
 
 pub use self::batch_executor::BatchExecutor;
 pub use self::batch_read::BatchRead;
+pub use self::bulk_write::BulkWriteSummary;
+pub use self::bulk_write_executor::BulkWriteExecutor;
@@ -23,20 +23,24 @@ use std::vec::Vec;
 
 use scoped_pool::Pool;
 
-use crate::batch::BatchExecutor;
+use crate::batch::{BatchExecutor, BulkWriteExecutor};
+use crate::cluster::partition::Partition;
 use crate::cluster::{Cluster, Node};
 use crate::commands::{
     DeleteCommand, ExecuteUDFCommand, ExistsCommand, OperateCommand, QueryCommand, ReadCommand,
     ScanCommand, TouchCommand, WriteCommand,
 };
 use crate::errors::{ErrorKind, Result, ResultExt};
+use crate::expressions::FilterExpression;
 use crate::net::ToHosts;
 use crate::operations::{Operation, OperationType};
-use crate::policy::{BatchPolicy, ClientPolicy, QueryPolicy, ReadPolicy, ScanPolicy, WritePolicy};
+use crate::policy::{
+    BatchPolicy, BulkWritePolicy, ClientPolicy, QueryPolicy, ReadPolicy, ScanPolicy, WritePolicy,
+};
 use crate::task::{IndexTask, RegisterTask};
 use crate::{
-    BatchRead, Bin, Bins, CollectionIndexType, IndexType, Key, Record, Recordset, ResultCode,
-    Statement, UDFLang, Value,
+    BatchRead, Bin, Bins, BulkWriteSummary, CollectionIndexType, IndexType, Key, Record, Recordset,
+    ResultCode, Statement, UDFLang, Value,
 };
 
 /// Instantiate a Client instance to access an Aerospike database cluster and perform database
@@ -136,6 +140,28 @@ impl Client {
         self.cluster.nodes()
     }
 
+    /// Return the node owning the master copy of the record with the given key, without sending a
+    /// request to the cluster. Fails if the owner of the key's partition is not known, e.g.
+    /// because the cluster is still being tended or the namespace does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use aerospike::*;
+    ///
+    /// # let hosts = std::env::var("AEROSPIKE_HOSTS").unwrap();
+    /// # let client = Client::new(&ClientPolicy::default(), &hosts).unwrap();
+    /// let key = as_key!("test", "test", "mykey");
+    /// match client.node_for_key(&key) {
+    ///     Ok(node) => println!("Partition {} is owned by {}", key.partition_id(), node),
+    ///     Err(err) => println!("Error looking up node: {}", err),
+    /// }
+    /// ```
+    pub fn node_for_key(&self, key: &Key) -> Result<Arc<Node>> {
+        let partition = Partition::new_by_key(key);
+        self.cluster.get_master_node(&partition)
+    }
+
     /// Read record for the specified key. Depending on the bins value provided, all record bins,
     /// only selected record bins or only the record headers will be returned. The policy can be
     /// used to specify timeouts.
@@ -286,6 +312,51 @@ impl Client {
         command.execute()
     }
 
+    /// Write a stream of records. Writes are pipelined across the cluster nodes using the client's
+    /// thread pool. The policy limits the number of write requests in flight, in total and per
+    /// node; once a limit is reached, no further records are taken from the input until pending
+    /// writes complete. Failed writes do not abort the operation, unless requested by the policy;
+    /// instead, the returned summary lists the keys of all records that could not be written.
+    ///
+    /// If the operation is aborted, records are no longer read from the input and the summary is
+    /// marked as `aborted`. To resume the operation, pass the input using `Iterator::by_ref` and
+    /// write the summary's `skipped` records as well as the records remaining in the input.
+    ///
+    /// # Examples
+    ///
+    /// Write 1,000 records and report any failures.
+    ///
+    /// ```rust
+    /// # use aerospike::*;
+    ///
+    /// # let hosts = std::env::var("AEROSPIKE_HOSTS").unwrap();
+    /// # let client = Client::new(&ClientPolicy::default(), &hosts).unwrap();
+    /// let records = (0..1_000).map(|i| {
+    ///     let key = as_key!("test", "test", i);
+    ///     (key, vec![as_bin!("i", i)])
+    /// });
+    /// match client.put_stream(&BulkWritePolicy::default(), records) {
+    ///     Ok(summary) => {
+    ///         println!("Records written: {}", summary.written);
+    ///         for (key, err) in summary.failures {
+    ///             println!("Error writing record {}: {}", key, err);
+    ///         }
+    ///     }
+    ///     Err(err) => println!("Error writing records: {}", err),
+    /// }
+    /// ```
+    pub fn put_stream<'b, I>(
+        &self,
+        policy: &BulkWritePolicy,
+        records: I,
+    ) -> Result<BulkWriteSummary>
+    where
+        I: IntoIterator<Item = (Key, Vec<Bin<'b>>)>,
+    {
+        let executor = BulkWriteExecutor::new(self.cluster.clone(), self.thread_pool.clone());
+        executor.execute_put(policy, records)
+    }
+
     /// Add integer bin values to existing record bin values. The policy specifies the transaction
     /// timeout, record expiration and how the transaction is handled when the record already
     /// exists. This call only works for integer values.
@@ -890,6 +961,93 @@ impl Client {
             .chain_err(|| "Error dropping index")
     }
 
+    /// Set the XDR filter for the given datacenter and namespace. Only records matching the
+    /// filter expression are shipped to the datacenter. Requires Aerospike Server version 5.3 or
+    /// later. See <https://www.aerospike.com/docs/reference/info#xdr-set-filter> for further info.
+    /// Use `clear_xdr_filter` to remove the filter.
+    ///
+    /// # Examples
+    ///
+    /// Only ship records whose "region" bin is "eu" to datacenter "dc-eu".
+    ///
+    /// ```rust,no_run
+    /// # use aerospike::*;
+    /// use aerospike::expressions::{eq, string_bin, string_val};
+    ///
+    /// # let hosts = std::env::var("AEROSPIKE_HOSTS").unwrap();
+    /// # let client = Client::new(&ClientPolicy::default(), &hosts).unwrap();
+    /// let filter = eq(string_bin("region"), string_val("eu"));
+    /// match client.set_xdr_filter(&WritePolicy::default(), "dc-eu", "test", &filter) {
+    ///     Ok(()) => println!("XDR filter set"),
+    ///     Err(err) => println!("Error setting XDR filter: {}", err),
+    /// }
+    /// ```
+    pub fn set_xdr_filter(
+        &self,
+        policy: &WritePolicy,
+        datacenter: &str,
+        namespace: &str,
+        filter: &FilterExpression,
+    ) -> Result<()> {
+        validate_xdr_args(datacenter, namespace)?;
+        let filter = filter.to_base64()?;
+
+        let cmd = format!(
+            "xdr-set-filter:dc={};namespace={};exp={}",
+            datacenter, namespace, filter
+        );
+        self.send_info_cmd(&cmd, policy)
+            .chain_err(|| "Error setting XDR filter")
+    }
+
+    /// Remove the XDR filter for the given datacenter and namespace, so that all records are
+    /// shipped to the datacenter again. Requires Aerospike Server version 5.3 or later.
+    pub fn clear_xdr_filter(
+        &self,
+        policy: &WritePolicy,
+        datacenter: &str,
+        namespace: &str,
+    ) -> Result<()> {
+        validate_xdr_args(datacenter, namespace)?;
+
+        let cmd = format!(
+            "xdr-set-filter:dc={};namespace={};exp=null",
+            datacenter, namespace
+        );
+        self.send_info_cmd(&cmd, policy)
+            .chain_err(|| "Error clearing XDR filter")
+    }
+
+    /// Return the XDR filter for the given datacenter and namespace, or `None` if no filter is
+    /// set. Requires Aerospike Server version 5.3 or later.
+    pub fn get_xdr_filter(
+        &self,
+        policy: &WritePolicy,
+        datacenter: &str,
+        namespace: &str,
+    ) -> Result<Option<FilterExpression>> {
+        validate_xdr_args(datacenter, namespace)?;
+
+        let cmd = format!("xdr-get-filter:dc={};namespace={}", datacenter, namespace);
+        let node = self.cluster.get_random_node()?;
+        // Sample response: {"xdr-get-filter:dc=dc1;namespace=test": "namespace=test:exp=kxGRSQ=="}
+        let response = node.info(policy.base_policy.timeout, &[&cmd])?;
+
+        match response.get(&cmd) {
+            Some(value) => {
+                check_info_response(value).chain_err(|| "Error getting XDR filter")?;
+                match parse_xdr_filter(value) {
+                    Some(filter) => Ok(Some(FilterExpression::from_base64(filter)?)),
+                    None => Ok(None),
+                }
+            }
+            None => bail!(ErrorKind::BadResponse(format!(
+                "Missing XDR filter in response: {:?}",
+                response
+            ))),
+        }
+    }
+
     fn send_info_cmd(&self, cmd: &str, policy: &WritePolicy) -> Result<()> {
         let node = self.cluster.get_random_node()?;
         let response = node.info(policy.base_policy.timeout, &[cmd])?;
@@ -897,14 +1055,111 @@ impl Client {
         if let Some(v) = response.values().next() {
             if v.to_uppercase() == "OK" {
                 return Ok(());
-            } else if v.starts_with("FAIL:") {
-                let result = v.split(':').nth(1).unwrap().parse::<u8>()?;
-                bail!(ErrorKind::ServerError(ResultCode::from(result)));
             }
+            check_info_response(v)?;
         }
 
-        bail!(ErrorKind::BadResponse(
-            "Unexpected sindex info command response".to_string()
-        ))
+        bail!(ErrorKind::BadResponse(format!(
+            "Unexpected info command response: {:?}",
+            response
+        )))
+    }
+}
+
+// Turn the error responses returned by info commands, `FAIL:<code>[:<message>]` and
+// `ERROR:<code>:<message>`, into errors. The result code is optional for `ERROR` responses.
+fn check_info_response(response: &str) -> Result<()> {
+    if !response.starts_with("FAIL:") && !response.starts_with("ERROR:") {
+        return Ok(());
+    }
+
+    match response.split(':').nth(1).map(str::parse::<u8>) {
+        Some(Ok(code)) => bail!(ErrorKind::ServerError(ResultCode::from(code))),
+        _ => bail!(ErrorKind::BadResponse(response.to_string())),
+    }
+}
+
+// Extract the base64 encoded filter expression from an `xdr-get-filter` response, e.g.
+// `namespace=test:exp=kxGRSQ==`.
+fn parse_xdr_filter(response: &str) -> Option<&str> {
+    let filter = response
+        .split(|c: char| c == ':' || c == ';')
+        .find(|field| field.starts_with("exp="))
+        .map(|field| &field[4..]);
+
+    match filter {
+        None | Some("") | Some("null") => None,
+        Some(filter) => Some(filter),
+    }
+}
+
+// Datacenter and namespace names are sent as info command parameters and must not contain any
+// of the parameter separators.
+fn validate_xdr_args(datacenter: &str, namespace: &str) -> Result<()> {
+    for (name, value) in &[("datacenter", datacenter), ("namespace", namespace)] {
+        if value.is_empty() {
+            bail!(ErrorKind::InvalidArgument(format!(
+                "XDR {} must not be empty",
+                name
+            )));
+        }
+        if value.contains(|c: char| c == ';' || c == ':' || c == '=' || c.is_whitespace()) {
+            bail!(ErrorKind::InvalidArgument(format!(
+                "Invalid XDR {}: {}",
+                name, value
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_info_response, parse_xdr_filter, validate_xdr_args};
+    use crate::errors::{Error, ErrorKind};
+    use crate::ResultCode;
+
+    #[test]
+    fn xdr_filter_from_response() {
+        assert_eq!(
+            parse_xdr_filter("namespace=test:exp=kxGRSQ=="),
+            Some("kxGRSQ==")
+        );
+        assert_eq!(parse_xdr_filter("namespace=test:exp=null"), None);
+        assert_eq!(parse_xdr_filter("namespace=test:exp="), None);
+        assert_eq!(parse_xdr_filter("namespace=test"), None);
+    }
+
+    #[test]
+    fn info_error_responses() {
+        assert!(check_info_response("ok").is_ok());
+        assert!(check_info_response("namespace=test:exp=null").is_ok());
+
+        match check_info_response("ERROR::unknown namespace") {
+            Err(Error(ErrorKind::BadResponse(msg), _)) => {
+                assert_eq!(msg, "ERROR::unknown namespace")
+            }
+            _ => panic!("Expected BadResponse error"),
+        }
+
+        match check_info_response("FAIL:4") {
+            Err(Error(ErrorKind::ServerError(rc), _)) => assert_eq!(rc, ResultCode::from(4)),
+            _ => panic!("Expected ServerError"),
+        }
+
+        match check_info_response("ERROR:4:bad filter") {
+            Err(Error(ErrorKind::ServerError(rc), _)) => assert_eq!(rc, ResultCode::from(4)),
+            _ => panic!("Expected ServerError"),
+        }
+    }
+
+    #[test]
+    fn xdr_args() {
+        assert!(validate_xdr_args("dc1", "test").is_ok());
+        assert!(validate_xdr_args("", "test").is_err());
+        assert!(validate_xdr_args("dc1", "").is_err());
+        assert!(validate_xdr_args("dc1;namespace=other", "test").is_err());
+        assert!(validate_xdr_args("dc1", "test:exp=null").is_err());
+        assert!(validate_xdr_args("dc1", "a=b").is_err());
     }
 }
@@ -481,6 +481,11 @@ impl Cluster {
     }
 
     pub fn get_node(&self, partition: &Partition) -> Result<Arc<Node>> {
+        self.get_master_node(partition)
+            .or_else(|_| self.get_random_node())
+    }
+
+    pub fn get_master_node(&self, partition: &Partition) -> Result<Arc<Node>> {
         let partitions = self.partitions();
         let partitions = partitions.read();
 
@@ -490,7 +495,10 @@ impl Cluster {
             }
         }
 
-        self.get_random_node()
+        bail!(ErrorKind::InvalidNode(format!(
+            "No master node for {}",
+            partition
+        )))
     }
 
     pub fn get_random_node(&self) -> Result<Arc<Node>> {
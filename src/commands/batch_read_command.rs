@@ -14,15 +14,14 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::thread;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use crate::batch::batch_executor::SharedSlice;
 use crate::cluster::Node;
-use crate::commands::{self, Command};
-use crate::errors::{ErrorKind, Result, ResultExt};
+use crate::commands::{self, SingleCommand};
+use crate::errors::{ErrorKind, Result};
 use crate::net::Connection;
-use crate::policy::{BatchPolicy, Policy, PolicyLike};
+use crate::policy::{BatchPolicy, Replica};
 use crate::{value, BatchRead, Record, ResultCode, Value};
 
 struct BatchRecord {
@@ -53,85 +52,7 @@ impl<'a, 'b> BatchReadCommand<'a, 'b> {
     }
 
     pub fn execute(&mut self) -> Result<()> {
-        let mut iterations = 0;
-        let base_policy = self.policy.base();
-
-        // set timeout outside the loop
-        let deadline = base_policy.deadline();
-
-        // Execute command until successful, timed out or maximum iterations have been reached.
-        loop {
-            iterations += 1;
-
-            // too many retries
-            if let Some(max_retries) = base_policy.max_retries() {
-                if iterations > max_retries + 1 {
-                    bail!(ErrorKind::Connection(format!(
-                        "Timeout after {} tries",
-                        iterations
-                    )));
-                }
-            }
-
-            // Sleep before trying again, after the first iteration
-            if iterations > 1 {
-                if let Some(sleep_between_retries) = base_policy.sleep_between_retries() {
-                    thread::sleep(sleep_between_retries);
-                }
-            }
-
-            // check for command timeout
-            if let Some(deadline) = deadline {
-                if Instant::now() > deadline {
-                    break;
-                }
-            }
-
-            // set command node, so when you return a record it has the node
-            let node = match self.get_node() {
-                Ok(node) => node,
-                _ => continue, // Node is currently inactive. Retry.
-            };
-
-            let mut conn = match node.get_connection(base_policy.timeout()) {
-                Ok(conn) => conn,
-                Err(err) => {
-                    warn!("Node {}: {}", node, err);
-                    continue;
-                }
-            };
-
-            self.prepare_buffer(&mut conn)
-                .chain_err(|| "Failed to prepare send buffer")?;
-            self.write_timeout(&mut conn, base_policy.timeout())
-                .chain_err(|| "Failed to set timeout for send buffer")?;
-
-            // Send command.
-            if let Err(err) = self.write_buffer(&mut conn) {
-                // IO errors are considered temporary anomalies. Retry.
-                // Close socket to flush out possible garbage. Do not put back in pool.
-                conn.invalidate();
-                warn!("Node {}: {}", node, err);
-                continue;
-            }
-
-            // Parse results.
-            if let Err(err) = self.parse_result(&mut conn) {
-                // close the connection
-                // cancelling/closing the batch/multi commands will return an error, which will
-                // close the connection to throw away its data and signal the server about the
-                // situation. We will not put back the connection in the buffer.
-                if !commands::keep_connection(&err) {
-                    conn.invalidate();
-                }
-                return Err(err);
-            }
-
-            // command has completed successfully.  Exit method.
-            return Ok(());
-        }
-
-        bail!(ErrorKind::Connection("Timeout".to_string()))
+        SingleCommand::execute(self.policy, self)
     }
 
     fn parse_group(&mut self, conn: &mut Connection, size: usize) -> Result<bool> {
@@ -221,7 +142,8 @@ impl<'a, 'b> commands::Command for BatchReadCommand<'a, 'b> {
         )
     }
 
-    fn get_node(&self) -> Result<Arc<Node>> {
+    fn get_node(&self, _replica: Replica) -> Result<Arc<Node>> {
+        // The command is bound to its node; the replica selected by the retry strategy is ignored.
         Ok(self.node.clone())
     }
 
@@ -19,7 +19,7 @@ use crate::cluster::{Cluster, Node};
 use crate::commands::{buffer, Command, SingleCommand};
 use crate::errors::{ErrorKind, Result};
 use crate::net::Connection;
-use crate::policy::WritePolicy;
+use crate::policy::{Replica, WritePolicy};
 use crate::{Key, ResultCode};
 
 pub struct DeleteCommand<'a> {
@@ -56,8 +56,8 @@ impl<'a> Command for DeleteCommand<'a> {
         conn.buffer.set_delete(self.policy, self.single_command.key)
     }
 
-    fn get_node(&self) -> Result<Arc<Node>> {
-        self.single_command.get_node()
+    fn get_node(&self, replica: Replica) -> Result<Arc<Node>> {
+        self.single_command.get_node(replica)
     }
 
     fn parse_result(&mut self, conn: &mut Connection) -> Result<()> {
@@ -20,7 +20,7 @@ use crate::cluster::{Cluster, Node};
 use crate::commands::{Command, ReadCommand, SingleCommand};
 use crate::errors::Result;
 use crate::net::Connection;
-use crate::policy::WritePolicy;
+use crate::policy::{Replica, WritePolicy};
 use crate::{Bins, Key, Value};
 
 pub struct ExecuteUDFCommand<'a> {
@@ -74,8 +74,8 @@ impl<'a> Command for ExecuteUDFCommand<'a> {
         )
     }
 
-    fn get_node(&self) -> Result<Arc<Node>> {
-        self.read_command.get_node()
+    fn get_node(&self, replica: Replica) -> Result<Arc<Node>> {
+        self.read_command.get_node(replica)
     }
 
     fn parse_result(&mut self, conn: &mut Connection) -> Result<()> {
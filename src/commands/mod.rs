@@ -52,13 +52,14 @@ pub use self::write_command::WriteCommand;
 use crate::cluster::Node;
 use crate::errors::{Error, ErrorKind, Result};
 use crate::net::Connection;
+use crate::policy::Replica;
 use crate::ResultCode;
 
 // Command interface describes all commands available
 pub trait Command {
     fn write_timeout(&mut self, conn: &mut Connection, timeout: Option<Duration>) -> Result<()>;
     fn prepare_buffer(&mut self, conn: &mut Connection) -> Result<()>;
-    fn get_node(&self) -> Result<Arc<Node>>;
+    fn get_node(&self, replica: Replica) -> Result<Arc<Node>>;
     fn parse_result(&mut self, conn: &mut Connection) -> Result<()>;
     fn write_buffer(&mut self, conn: &mut Connection) -> Result<()>;
 }
@@ -72,3 +73,12 @@ pub fn keep_connection(err: &Error) -> bool {
         _ => false,
     }
 }
+
+// Errors returned by the server are definite results of the command. Such commands are not
+// retried.
+pub fn is_server_result(err: &Error) -> bool {
+    match *err {
+        Error(ErrorKind::ServerError(_), _) | Error(ErrorKind::UdfBadResponse(_), _) => true,
+        _ => false,
+    }
+}
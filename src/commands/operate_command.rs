@@ -20,7 +20,7 @@ use crate::commands::{Command, ReadCommand, SingleCommand};
 use crate::errors::Result;
 use crate::net::Connection;
 use crate::operations::Operation;
-use crate::policy::WritePolicy;
+use crate::policy::{Replica, WritePolicy};
 use crate::{Bins, Key};
 
 pub struct OperateCommand<'a> {
@@ -66,8 +66,8 @@ impl<'a> Command for OperateCommand<'a> {
         )
     }
 
-    fn get_node(&self) -> Result<Arc<Node>> {
-        self.read_command.get_node()
+    fn get_node(&self, replica: Replica) -> Result<Arc<Node>> {
+        self.read_command.get_node(replica)
     }
 
     fn parse_result(&mut self, conn: &mut Connection) -> Result<()> {
@@ -19,7 +19,7 @@ use crate::cluster::Node;
 use crate::commands::{Command, SingleCommand, StreamCommand};
 use crate::errors::Result;
 use crate::net::Connection;
-use crate::policy::QueryPolicy;
+use crate::policy::{QueryPolicy, Replica};
 use crate::{Recordset, Statement};
 
 pub struct QueryCommand<'a> {
@@ -66,8 +66,8 @@ impl<'a> Command for QueryCommand<'a> {
         )
     }
 
-    fn get_node(&self) -> Result<Arc<Node>> {
-        self.stream_command.get_node()
+    fn get_node(&self, replica: Replica) -> Result<Arc<Node>> {
+        self.stream_command.get_node(replica)
     }
 
     fn parse_result(&mut self, conn: &mut Connection) -> Result<()> {
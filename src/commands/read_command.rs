@@ -22,7 +22,7 @@ use crate::commands::buffer;
 use crate::commands::{Command, SingleCommand};
 use crate::errors::{ErrorKind, Result};
 use crate::net::Connection;
-use crate::policy::ReadPolicy;
+use crate::policy::{ReadPolicy, Replica};
 use crate::value::bytes_to_particle;
 use crate::{Bins, Key, Record, ResultCode, Value};
 
@@ -110,8 +110,8 @@ impl<'a> Command for ReadCommand<'a> {
             .set_read(self.policy, self.single_command.key, &self.bins)
     }
 
-    fn get_node(&self) -> Result<Arc<Node>> {
-        self.single_command.get_node()
+    fn get_node(&self, replica: Replica) -> Result<Arc<Node>> {
+        self.single_command.get_node(replica)
     }
 
     fn parse_result(&mut self, conn: &mut Connection) -> Result<()> {
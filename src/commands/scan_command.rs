@@ -20,7 +20,7 @@ use crate::cluster::Node;
 use crate::commands::{Command, SingleCommand, StreamCommand};
 use crate::errors::Result;
 use crate::net::Connection;
-use crate::policy::ScanPolicy;
+use crate::policy::{Replica, ScanPolicy};
 use crate::{Bins, Recordset};
 
 pub struct ScanCommand<'a> {
@@ -74,8 +74,8 @@ impl<'a> Command for ScanCommand<'a> {
         )
     }
 
-    fn get_node(&self) -> Result<Arc<Node>> {
-        self.stream_command.get_node()
+    fn get_node(&self, replica: Replica) -> Result<Arc<Node>> {
+        self.stream_command.get_node(replica)
     }
 
     fn parse_result(&mut self, conn: &mut Connection) -> Result<()> {
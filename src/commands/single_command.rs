@@ -19,9 +19,12 @@ use std::time::Instant;
 use crate::cluster::partition::Partition;
 use crate::cluster::{Cluster, Node};
 use crate::commands::{self};
-use crate::errors::{ErrorKind, Result, ResultExt};
+use crate::errors::{Error, ErrorKind, Result, ResultExt};
 use crate::net::Connection;
-use crate::policy::Policy;
+use crate::policy::{
+    DefaultRetryStrategy, FailedAttempt, Policy, Replica, RetryDecision, RetryStrategy,
+    MAX_RETRY_HISTORY,
+};
 use crate::Key;
 
 pub struct SingleCommand<'a> {
@@ -40,8 +43,11 @@ impl<'a> SingleCommand<'a> {
         }
     }
 
-    pub fn get_node(&self) -> Result<Arc<Node>> {
-        self.cluster.get_node(&self.partition)
+    pub fn get_node(&self, replica: Replica) -> Result<Arc<Node>> {
+        match replica {
+            Replica::Master => self.cluster.get_node(&self.partition),
+            Replica::Random => self.cluster.get_random_node(),
+        }
     }
 
     pub fn empty_socket(conn: &mut Connection) -> Result<()> {
@@ -64,29 +70,38 @@ impl<'a> SingleCommand<'a> {
     //
 
     pub fn execute(policy: &dyn Policy, cmd: &'a mut dyn commands::Command) -> Result<()> {
-        let mut iterations = 0;
+        let default_strategy;
+        let strategy: &dyn RetryStrategy = match policy.retry_strategy() {
+            Some(strategy) => strategy,
+            None => {
+                default_strategy =
+                    DefaultRetryStrategy::new(policy.max_retries(), policy.sleep_between_retries());
+                &default_strategy
+            }
+        };
+
+        let mut attempts = 0;
+        let mut history: Vec<FailedAttempt> = vec![];
+        let mut replica = Replica::Master;
 
         // set timeout outside the loop
         let deadline = policy.deadline();
 
-        // Execute command until successful, timed out or maximum iterations have been reached.
+        // Execute command until successful, timed out or the retry strategy gives up.
         loop {
-            iterations += 1;
-
-            // too many retries
-            if let Some(max_retries) = policy.max_retries() {
-                if iterations > max_retries + 1 {
-                    bail!(ErrorKind::Connection(format!(
-                        "Timeout after {} tries",
-                        iterations
-                    )));
-                }
-            }
-
-            // Sleep before trying again, after the first iteration
-            if iterations > 1 {
-                if let Some(sleep_between_retries) = policy.sleep_between_retries() {
-                    thread::sleep(sleep_between_retries);
+            // Consult the retry strategy before trying again, after the first iteration
+            if !history.is_empty() {
+                match strategy.next_attempt(attempts, &history) {
+                    RetryDecision::Abort => return Err(SingleCommand::abort(attempts, history)),
+                    RetryDecision::Retry {
+                        delay,
+                        replica: next_replica,
+                    } => {
+                        if let Some(delay) = delay {
+                            thread::sleep(delay);
+                        }
+                        replica = next_replica;
+                    }
                 }
             }
 
@@ -97,16 +112,39 @@ impl<'a> SingleCommand<'a> {
                 }
             }
 
+            attempts += 1;
+
             // set command node, so when you return a record it has the node
-            let node = match cmd.get_node() {
+            let node = match SingleCommand::select_node(cmd, replica, &history) {
                 Ok(node) => node,
-                _ => continue, // Node is currently inactive. Retry.
+                Err(err) => {
+                    // Node is currently inactive. Retry.
+                    SingleCommand::record_failure(
+                        &mut history,
+                        FailedAttempt {
+                            node: None,
+                            replica,
+                            error: err,
+                            in_doubt: false,
+                        },
+                    );
+                    continue;
+                }
             };
 
             let mut conn = match node.get_connection(policy.timeout()) {
                 Ok(conn) => conn,
                 Err(err) => {
                     warn!("Node {}: {}", node, err);
+                    SingleCommand::record_failure(
+                        &mut history,
+                        FailedAttempt {
+                            node: Some(node),
+                            replica,
+                            error: err,
+                            in_doubt: false,
+                        },
+                    );
                     continue;
                 }
             };
@@ -122,6 +160,15 @@ impl<'a> SingleCommand<'a> {
                 // Close socket to flush out possible garbage. Do not put back in pool.
                 conn.invalidate();
                 warn!("Node {}: {}", node, err);
+                SingleCommand::record_failure(
+                    &mut history,
+                    FailedAttempt {
+                        node: Some(node),
+                        replica,
+                        error: err,
+                        in_doubt: false,
+                    },
+                );
                 continue;
             }
 
@@ -134,7 +181,22 @@ impl<'a> SingleCommand<'a> {
                 if !commands::keep_connection(&err) {
                     conn.invalidate();
                 }
-                return Err(err);
+
+                // The server processed the command; its result is final.
+                if commands::is_server_result(&err) {
+                    return Err(err);
+                }
+
+                SingleCommand::record_failure(
+                    &mut history,
+                    FailedAttempt {
+                        node: Some(node),
+                        replica,
+                        error: err,
+                        in_doubt: true,
+                    },
+                );
+                continue;
             }
 
             // command has completed successfully.  Exit method.
@@ -143,4 +205,215 @@ impl<'a> SingleCommand<'a> {
 
         bail!(ErrorKind::Connection("Timeout".to_string()))
     }
+
+    // Select the node for the next attempt. Random replicas skip nodes that failed earlier
+    // attempts, unless no other active node is found.
+    fn select_node(
+        cmd: &dyn commands::Command,
+        replica: Replica,
+        history: &[FailedAttempt],
+    ) -> Result<Arc<Node>> {
+        let node = cmd.get_node(replica)?;
+        if replica != Replica::Random {
+            return Ok(node);
+        }
+
+        let failed = |node: &Arc<Node>| {
+            history
+                .iter()
+                .any(|failure| failure.node.as_ref() == Some(node))
+        };
+        if !failed(&node) {
+            return Ok(node);
+        }
+
+        // Random nodes are selected round-robin; skip at most one node per failed attempt.
+        for _ in 0..history.len() {
+            let candidate = cmd.get_node(replica)?;
+            if !failed(&candidate) {
+                return Ok(candidate);
+            }
+        }
+        Ok(node)
+    }
+
+    // Add a failed attempt to the history, keeping only the most recent attempts.
+    fn record_failure(history: &mut Vec<FailedAttempt>, failure: FailedAttempt) {
+        if history.len() >= MAX_RETRY_HISTORY {
+            history.remove(0);
+        }
+        history.push(failure);
+    }
+
+    // Turn the last failed attempt into the error returned to the caller once the retry strategy
+    // aborted the command. Errors received after the command was sent are returned as-is.
+    fn abort(attempts: usize, mut history: Vec<FailedAttempt>) -> Error {
+        let timeout = ErrorKind::Connection(format!("Timeout after {} tries", attempts));
+        let failure = match history.pop() {
+            Some(failure) => failure,
+            None => return timeout.into(),
+        };
+
+        if failure.in_doubt {
+            return failure.error;
+        }
+        Error::with_chain(failure.error, timeout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use parking_lot::Mutex;
+
+    use super::SingleCommand;
+    use crate::cluster::Node;
+    use crate::commands::Command;
+    use crate::errors::{Error, ErrorKind, Result};
+    use crate::net::Connection;
+    use crate::policy::{FailedAttempt, Replica, RetryDecision, RetryStrategy, MAX_RETRY_HISTORY};
+    use crate::ReadPolicy;
+
+    // Command that never finds an active node; records the requested replicas.
+    #[derive(Default)]
+    struct NoActiveNode {
+        replicas: RefCell<Vec<Replica>>,
+    }
+
+    impl Command for NoActiveNode {
+        fn write_timeout(
+            &mut self,
+            _conn: &mut Connection,
+            _timeout: Option<Duration>,
+        ) -> Result<()> {
+            unreachable!()
+        }
+
+        fn prepare_buffer(&mut self, _conn: &mut Connection) -> Result<()> {
+            unreachable!()
+        }
+
+        fn get_node(&self, replica: Replica) -> Result<Arc<Node>> {
+            self.replicas.borrow_mut().push(replica);
+            bail!("No active node")
+        }
+
+        fn parse_result(&mut self, _conn: &mut Connection) -> Result<()> {
+            unreachable!()
+        }
+
+        fn write_buffer(&mut self, _conn: &mut Connection) -> Result<()> {
+            unreachable!()
+        }
+    }
+
+    // Retries once on a random node, then gives up.
+    #[derive(Debug)]
+    struct RandomOnce;
+
+    impl RetryStrategy for RandomOnce {
+        fn next_attempt(&self, attempt: usize, _history: &[FailedAttempt]) -> RetryDecision {
+            if attempt > 1 {
+                return RetryDecision::Abort;
+            }
+            RetryDecision::Retry {
+                delay: None,
+                replica: Replica::Random,
+            }
+        }
+    }
+
+    // Retries a fixed number of times; records the length of the history it is passed.
+    #[derive(Debug, Default)]
+    struct HistoryLengths {
+        lengths: Mutex<Vec<usize>>,
+    }
+
+    impl RetryStrategy for HistoryLengths {
+        fn next_attempt(&self, attempt: usize, history: &[FailedAttempt]) -> RetryDecision {
+            self.lengths.lock().push(history.len());
+            if attempt >= MAX_RETRY_HISTORY + 2 {
+                return RetryDecision::Abort;
+            }
+            RetryDecision::Retry {
+                delay: None,
+                replica: Replica::Master,
+            }
+        }
+    }
+
+    fn failure(in_doubt: bool) -> FailedAttempt {
+        FailedAttempt {
+            node: None,
+            replica: Replica::Master,
+            error: ErrorKind::BadResponse("test".to_string()).into(),
+            in_doubt,
+        }
+    }
+
+    #[test]
+    fn retry_strategy_selects_replica() {
+        let mut policy = ReadPolicy::default();
+        policy.retry_strategy = Some(Arc::new(RandomOnce));
+        let mut cmd = NoActiveNode::default();
+
+        let err = SingleCommand::execute(&policy, &mut cmd).unwrap_err();
+        match err {
+            Error(ErrorKind::Connection(msg), _) => assert_eq!(msg, "Timeout after 2 tries"),
+            err => panic!("Unexpected error: {}", err),
+        }
+        assert_eq!(
+            *cmd.replicas.borrow(),
+            vec![Replica::Master, Replica::Random]
+        );
+    }
+
+    #[test]
+    fn default_strategy_uses_max_retries() {
+        let mut policy = ReadPolicy::default();
+        policy.max_retries = Some(3);
+        policy.sleep_between_retries = None;
+        let mut cmd = NoActiveNode::default();
+
+        assert!(SingleCommand::execute(&policy, &mut cmd).is_err());
+        assert_eq!(*cmd.replicas.borrow(), vec![Replica::Master; 4]);
+    }
+
+    #[test]
+    fn retry_history_is_bounded() {
+        let strategy = Arc::new(HistoryLengths::default());
+        let mut policy = ReadPolicy::default();
+        policy.retry_strategy = Some(strategy.clone());
+        let mut cmd = NoActiveNode::default();
+
+        assert!(SingleCommand::execute(&policy, &mut cmd).is_err());
+
+        let mut expected: Vec<usize> = (1..=MAX_RETRY_HISTORY).collect();
+        expected.extend(&[MAX_RETRY_HISTORY, MAX_RETRY_HISTORY]);
+        assert_eq!(*strategy.lengths.lock(), expected);
+    }
+
+    #[test]
+    fn abort_returns_in_doubt_error_unchanged() {
+        match SingleCommand::abort(2, vec![failure(false), failure(true)]) {
+            Error(ErrorKind::BadResponse(msg), _) => assert_eq!(msg, "test"),
+            err => panic!("Unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn abort_wraps_failure_before_send() {
+        let err = SingleCommand::abort(3, vec![failure(true), failure(false)]);
+        match err {
+            Error(ErrorKind::Connection(ref msg), _) => assert_eq!(msg, "Timeout after 3 tries"),
+            ref err => panic!("Unexpected error: {}", err),
+        }
+        match err.iter().nth(1) {
+            Some(cause) => assert_eq!(cause.to_string(), "Bad Server Response: test"),
+            None => panic!("Missing cause"),
+        }
+    }
 }
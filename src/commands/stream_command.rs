@@ -23,6 +23,7 @@ use crate::commands::field_type::FieldType;
 use crate::commands::Command;
 use crate::errors::{ErrorKind, Result};
 use crate::net::Connection;
+use crate::policy::Replica;
 use crate::query::Recordset;
 use crate::value::bytes_to_particle;
 use crate::{Key, Record, ResultCode, Value};
@@ -187,7 +188,8 @@ impl Command for StreamCommand {
         unreachable!()
     }
 
-    fn get_node(&self) -> Result<Arc<Node>> {
+    fn get_node(&self, _replica: Replica) -> Result<Arc<Node>> {
+        // The command is bound to its node; the replica selected by the retry strategy is ignored.
         Ok(self.node.clone())
     }
 
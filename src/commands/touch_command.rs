@@ -20,7 +20,7 @@ use crate::commands::buffer;
 use crate::commands::{Command, SingleCommand};
 use crate::errors::{ErrorKind, Result};
 use crate::net::Connection;
-use crate::policy::WritePolicy;
+use crate::policy::{Replica, WritePolicy};
 use crate::{Key, ResultCode};
 
 pub struct TouchCommand<'a> {
@@ -55,8 +55,8 @@ impl<'a> Command for TouchCommand<'a> {
         conn.buffer.set_touch(self.policy, self.single_command.key)
     }
 
-    fn get_node(&self) -> Result<Arc<Node>> {
-        self.single_command.get_node()
+    fn get_node(&self, replica: Replica) -> Result<Arc<Node>> {
+        self.single_command.get_node(replica)
     }
 
     fn parse_result(&mut self, conn: &mut Connection) -> Result<()> {
@@ -0,0 +1,373 @@
+// Copyright 2015-2020 Aerospike, Inc.
+//
+// Portions may be licensed to Aerospike, Inc. under one or more contributor
+// license agreements.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Functions used to create filter expressions, e.g. for XDR filters. Requires server version
+//! 5.2 or later.
+//!
+//! # Examples
+//!
+//! Filter records whose "region" bin is "eu" and that do not expire within the next hour.
+//!
+//! ```rust
+//! use aerospike::expressions::{and, eq, ge, int_val, string_bin, string_val, ttl};
+//!
+//! let filter = and(vec![
+//!     eq(string_bin("region"), string_val("eu")),
+//!     ge(ttl(), int_val(3600)),
+//! ]);
+//! ```
+
+use crate::commands::buffer::Buffer;
+use crate::errors::Result;
+use crate::msgpack::encoder;
+use crate::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExpOp {
+    Eq = 1,
+    Ne = 2,
+    Gt = 3,
+    Ge = 4,
+    Lt = 5,
+    Le = 6,
+    And = 16,
+    Or = 17,
+    Not = 18,
+    DigestModulo = 64,
+    DeviceSize = 65,
+    LastUpdate = 66,
+    SinceUpdate = 67,
+    VoidTime = 68,
+    Ttl = 69,
+    SetName = 70,
+    KeyExists = 71,
+    IsTombstone = 72,
+    Key = 80,
+    Bin = 81,
+    BinType = 82,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExpType {
+    Int = 2,
+    String = 3,
+    Blob = 6,
+    Float = 7,
+}
+
+#[derive(Debug, Clone)]
+enum ExpArg {
+    Value(Value),
+    Name(String),
+    Exp(FilterExpression),
+}
+
+#[derive(Debug, Clone)]
+enum ExpData {
+    // Command and its arguments; packed as `[op, args...]`.
+    Cmd(ExpOp, Vec<ExpArg>),
+    Value(Value),
+    // Expression in its wire format, e.g. as returned by the server.
+    Packed(Vec<u8>),
+}
+
+/// Filter expression, evaluated by the server against each record. Filter expressions are
+/// created using the functions provided in this module. Use `to_base64` to compare expressions.
+#[derive(Debug, Clone)]
+pub struct FilterExpression {
+    data: ExpData,
+}
+
+impl FilterExpression {
+    /// Create a filter expression from its base64 encoded wire format, e.g. as returned by
+    /// `to_base64` or by the `Expression.getBase64()` method of the Aerospike Java client.
+    pub fn from_base64(encoded: &str) -> Result<Self> {
+        let bytes = base64::decode(encoded)?;
+        Ok(FilterExpression {
+            data: ExpData::Packed(bytes),
+        })
+    }
+
+    /// Return the base64 encoded wire format of the filter expression.
+    pub fn to_base64(&self) -> Result<String> {
+        let size = self.pack(&mut None)?;
+        let mut buffer = Buffer::new();
+        buffer.resize_buffer(size)?;
+        self.pack(&mut Some(&mut buffer))?;
+        Ok(base64::encode(&buffer.data_buffer))
+    }
+
+    fn pack(&self, buf: &mut Option<&mut Buffer>) -> Result<usize> {
+        match self.data {
+            ExpData::Cmd(op, ref args) => {
+                let mut size = encoder::pack_array_begin(buf, args.len() + 1)?;
+                size += encoder::pack_value(buf, &Value::from(op as i64))?;
+                for arg in args {
+                    size += match *arg {
+                        ExpArg::Value(ref val) => encoder::pack_value(buf, val)?,
+                        ExpArg::Name(ref name) => encoder::pack_raw_string(buf, name)?,
+                        ExpArg::Exp(ref exp) => exp.pack(buf)?,
+                    };
+                }
+                Ok(size)
+            }
+            ExpData::Value(ref val) => encoder::pack_value(buf, val),
+            ExpData::Packed(ref bytes) => {
+                if let Some(ref mut buf) = *buf {
+                    buf.write_bytes(bytes)?;
+                }
+                Ok(bytes.len())
+            }
+        }
+    }
+}
+
+const fn cmd(op: ExpOp, args: Vec<ExpArg>) -> FilterExpression {
+    FilterExpression {
+        data: ExpData::Cmd(op, args),
+    }
+}
+
+fn value<V: Into<Value>>(val: V) -> FilterExpression {
+    FilterExpression {
+        data: ExpData::Value(val.into()),
+    }
+}
+
+fn args(exps: Vec<FilterExpression>) -> Vec<ExpArg> {
+    exps.into_iter().map(ExpArg::Exp).collect()
+}
+
+fn bin(name: &str, exp_type: ExpType) -> FilterExpression {
+    cmd(
+        ExpOp::Bin,
+        vec![
+            ExpArg::Value(Value::from(exp_type as i64)),
+            ExpArg::Name(name.to_owned()),
+        ],
+    )
+}
+
+fn key(exp_type: ExpType) -> FilterExpression {
+    cmd(
+        ExpOp::Key,
+        vec![ExpArg::Value(Value::from(exp_type as i64))],
+    )
+}
+
+/// Create integer value.
+pub fn int_val(val: i64) -> FilterExpression {
+    value(val)
+}
+
+/// Create float value.
+pub fn float_val(val: f64) -> FilterExpression {
+    value(val)
+}
+
+/// Create string value.
+pub fn string_val<S: Into<String>>(val: S) -> FilterExpression {
+    value(val.into())
+}
+
+/// Create boolean value.
+pub fn bool_val(val: bool) -> FilterExpression {
+    value(val)
+}
+
+/// Create blob value.
+pub fn blob_val(val: Vec<u8>) -> FilterExpression {
+    value(val)
+}
+
+/// Create nil value.
+pub fn nil() -> FilterExpression {
+    value(Value::Nil)
+}
+
+/// Create expression that returns the value of an integer bin.
+pub fn int_bin(name: &str) -> FilterExpression {
+    bin(name, ExpType::Int)
+}
+
+/// Create expression that returns the value of a float bin.
+pub fn float_bin(name: &str) -> FilterExpression {
+    bin(name, ExpType::Float)
+}
+
+/// Create expression that returns the value of a string bin.
+pub fn string_bin(name: &str) -> FilterExpression {
+    bin(name, ExpType::String)
+}
+
+/// Create expression that returns the value of a blob bin.
+pub fn blob_bin(name: &str) -> FilterExpression {
+    bin(name, ExpType::Blob)
+}
+
+/// Create expression that returns the particle type of a bin, or 0 if the bin does not exist.
+pub fn bin_type(name: &str) -> FilterExpression {
+    cmd(ExpOp::BinType, vec![ExpArg::Name(name.to_owned())])
+}
+
+/// Create expression that returns true if the bin exists.
+pub fn bin_exists(name: &str) -> FilterExpression {
+    ne(bin_type(name), int_val(0))
+}
+
+/// Create expression that returns the integer user key of the record. The user key is only
+/// available if it was stored with the record.
+pub fn int_key() -> FilterExpression {
+    key(ExpType::Int)
+}
+
+/// Create expression that returns the string user key of the record. The user key is only
+/// available if it was stored with the record.
+pub fn string_key() -> FilterExpression {
+    key(ExpType::String)
+}
+
+/// Create expression that returns true if the user key is stored with the record.
+pub fn key_exists() -> FilterExpression {
+    cmd(ExpOp::KeyExists, vec![])
+}
+
+/// Create expression that returns the name of the record's set.
+pub fn set_name() -> FilterExpression {
+    cmd(ExpOp::SetName, vec![])
+}
+
+/// Create expression that returns the size of the record on disk, in bytes.
+pub fn device_size() -> FilterExpression {
+    cmd(ExpOp::DeviceSize, vec![])
+}
+
+/// Create expression that returns the time the record was last updated, in nanoseconds since
+/// the Unix epoch.
+pub fn last_update() -> FilterExpression {
+    cmd(ExpOp::LastUpdate, vec![])
+}
+
+/// Create expression that returns the time since the record was last updated, in milliseconds.
+pub fn since_update() -> FilterExpression {
+    cmd(ExpOp::SinceUpdate, vec![])
+}
+
+/// Create expression that returns the expiration time of the record, in nanoseconds since the
+/// Unix epoch.
+pub fn void_time() -> FilterExpression {
+    cmd(ExpOp::VoidTime, vec![])
+}
+
+/// Create expression that returns the time-to-live of the record, in seconds.
+pub fn ttl() -> FilterExpression {
+    cmd(ExpOp::Ttl, vec![])
+}
+
+/// Create expression that returns true if the record is a tombstone.
+pub fn is_tombstone() -> FilterExpression {
+    cmd(ExpOp::IsTombstone, vec![])
+}
+
+/// Create expression that returns the record digest modulo the given integer.
+pub fn digest_modulo(modulo: i64) -> FilterExpression {
+    cmd(
+        ExpOp::DigestModulo,
+        vec![ExpArg::Value(Value::from(modulo))],
+    )
+}
+
+/// Create equals (==) expression.
+pub fn eq(left: FilterExpression, right: FilterExpression) -> FilterExpression {
+    cmd(ExpOp::Eq, args(vec![left, right]))
+}
+
+/// Create not equal (!=) expression.
+pub fn ne(left: FilterExpression, right: FilterExpression) -> FilterExpression {
+    cmd(ExpOp::Ne, args(vec![left, right]))
+}
+
+/// Create greater than (>) expression.
+pub fn gt(left: FilterExpression, right: FilterExpression) -> FilterExpression {
+    cmd(ExpOp::Gt, args(vec![left, right]))
+}
+
+/// Create greater than or equal (>=) expression.
+pub fn ge(left: FilterExpression, right: FilterExpression) -> FilterExpression {
+    cmd(ExpOp::Ge, args(vec![left, right]))
+}
+
+/// Create less than (<) expression.
+pub fn lt(left: FilterExpression, right: FilterExpression) -> FilterExpression {
+    cmd(ExpOp::Lt, args(vec![left, right]))
+}
+
+/// Create less than or equal (<=) expression.
+pub fn le(left: FilterExpression, right: FilterExpression) -> FilterExpression {
+    cmd(ExpOp::Le, args(vec![left, right]))
+}
+
+/// Create "and" (&&) expression.
+pub fn and(exps: Vec<FilterExpression>) -> FilterExpression {
+    cmd(ExpOp::And, args(exps))
+}
+
+/// Create "or" (||) expression.
+pub fn or(exps: Vec<FilterExpression>) -> FilterExpression {
+    cmd(ExpOp::Or, args(exps))
+}
+
+/// Create "not" (!) expression.
+pub fn not(exp: FilterExpression) -> FilterExpression {
+    cmd(ExpOp::Not, args(vec![exp]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int_bin_eq() {
+        let exp = eq(int_bin("a"), int_val(1));
+        assert_eq!(exp.to_base64().unwrap(), "kwGTUQKhYQE=");
+
+        let exp = eq(int_bin("a"), int_val(1 << 40));
+        assert_eq!(exp.to_base64().unwrap(), "kwGTUQKhYdMAAAEAAAAAAA==");
+    }
+
+    #[test]
+    fn string_bin_eq() {
+        let exp = eq(string_bin("s"), string_val("x"));
+        assert_eq!(exp.to_base64().unwrap(), "kwGTUQOhc6IDeA==");
+    }
+
+    #[test]
+    fn nested_expressions() {
+        let exp = and(vec![gt(ttl(), int_val(100)), not(bin_exists("b"))]);
+        assert_eq!(exp.to_base64().unwrap(), "kxCTA5FFzGSSEpMCklKhYgA=");
+
+        let exp = eq(digest_modulo(3), int_val(1));
+        assert_eq!(exp.to_base64().unwrap(), "kwGSQAMB");
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        let exp = eq(int_bin("a"), int_val(1));
+        let decoded = FilterExpression::from_base64(&exp.to_base64().unwrap()).unwrap();
+        assert_eq!(decoded.to_base64().unwrap(), "kwGTUQKhYQE=");
+
+        assert!(FilterExpression::from_base64("not base64!").is_err());
+    }
+}
@@ -16,9 +16,11 @@
 use std::fmt;
 use std::result::Result as StdResult;
 
+use crate::cluster::node;
 use crate::errors::Result;
 use crate::Value;
 
+use byteorder::{ByteOrder, LittleEndian};
 use ripemd160::digest::Digest;
 use ripemd160::Ripemd160;
 
@@ -62,6 +64,15 @@ impl Key {
         Ok(key)
     }
 
+    /// Return the ID of the partition the record belongs to. The partition ID is derived from the
+    /// key's digest and determines which cluster node owns the record.
+    pub fn partition_id(&self) -> usize {
+        // CAN'T USE MOD directly - mod will give negative numbers.
+        // First AND makes positive and negative correctly, then mod.
+        // For any x, y : x % 2^y = x & (2^y - 1); the second method is twice as fast
+        LittleEndian::read_u32(&self.digest[0..4]) as usize & (node::PARTITIONS - 1)
+    }
+
     fn compute_digest(&mut self) -> Result<()> {
         let mut hash = Ripemd160::new();
         hash.input(self.set_name.as_bytes());
@@ -263,6 +274,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn partition_id() {
+        assert_eq!(as_key!("namespace", "set", 0).partition_id(), 2451);
+        assert_eq!(as_key!("namespace", "set", "haha").partition_id(), 2870);
+    }
+
     #[test]
     #[should_panic(expected = "Data type is not supported as Key value.")]
     fn unsupported_float_key() {
@@ -150,7 +150,7 @@ extern crate pwhash;
 extern crate rand;
 extern crate scoped_pool;
 
-pub use batch::BatchRead;
+pub use batch::{BatchRead, BulkWriteSummary};
 pub use bin::{Bin, Bins};
 pub use client::Client;
 pub use errors::{Error, ErrorKind, Result};
@@ -158,8 +158,9 @@ pub use key::Key;
 pub use net::Host;
 pub use operations::{MapPolicy, MapReturnType, MapWriteMode};
 pub use policy::{
-    BatchPolicy, ClientPolicy, CommitLevel, Concurrency, ConsistencyLevel, Expiration,
-    GenerationPolicy, Policy, Priority, QueryPolicy, ReadPolicy, RecordExistsAction, ScanPolicy,
+    BatchPolicy, BulkWritePolicy, ClientPolicy, CommitLevel, Concurrency, ConsistencyLevel,
+    DefaultRetryStrategy, Expiration, FailedAttempt, GenerationPolicy, Policy, Priority,
+    QueryPolicy, ReadPolicy, RecordExistsAction, Replica, RetryDecision, RetryStrategy, ScanPolicy,
     WritePolicy,
 };
 pub use query::{CollectionIndexType, IndexType, Recordset, Statement, UDFLang};
@@ -181,6 +182,7 @@ mod batch;
 mod client;
 mod cluster;
 mod commands;
+pub mod expressions;
 mod msgpack;
 mod net;
 pub mod operations;
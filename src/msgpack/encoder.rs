@@ -101,7 +101,7 @@ const MSGPACK_MARKER_BOOL_FALSE: u8 = 0xc2;
 const MSGPACK_MARKER_I8: u8 = 0xcc;
 const MSGPACK_MARKER_I16: u8 = 0xcd;
 const MSGPACK_MARKER_I32: u8 = 0xce;
-// const MSGPACK_MARKER_I64: u8 = 0xd3;
+const MSGPACK_MARKER_I64: u8 = 0xd3;
 
 const MSGPACK_MARKER_NI8: u8 = 0xd0;
 const MSGPACK_MARKER_NI16: u8 = 0xd1;
@@ -158,7 +158,7 @@ fn pack_map_begin(buf: &mut Option<&mut Buffer>, length: usize) -> Result<usize>
     }
 }
 
-fn pack_array_begin(buf: &mut Option<&mut Buffer>, length: usize) -> Result<usize> {
+pub fn pack_array_begin(buf: &mut Option<&mut Buffer>, length: usize) -> Result<usize> {
     match length {
         val if val < 16 => pack_half_byte(buf, 0x90 | (length as u8)),
         val if val >= 16 && val < 2 ^ 16 => pack_i16(buf, 0xdc, length as i16),
@@ -198,6 +198,18 @@ fn pack_string(buf: &mut Option<&mut Buffer>, val: &str) -> Result<usize> {
     Ok(size)
 }
 
+// Packs a string without the particle type prefix used for string values, e.g. for bin names.
+pub fn pack_raw_string(buf: &mut Option<&mut Buffer>, val: &str) -> Result<usize> {
+    let mut size = val.len();
+
+    size += pack_byte_array_begin(buf, size)?;
+    if let Some(ref mut buf) = *buf {
+        buf.write_str(val)?;
+    }
+
+    Ok(size)
+}
+
 fn pack_geo_json(buf: &mut Option<&mut Buffer>, val: &str) -> Result<usize> {
     let mut size = val.len() + 1;
 
@@ -222,7 +234,7 @@ fn pack_integer(buf: &mut Option<&mut Buffer>, val: i64) -> Result<usize> {
         val if val >= i64::from(i16::max_value()) && val < i64::from(i32::max_value()) => {
             pack_i32(buf, MSGPACK_MARKER_I32, val as i32)
         }
-        val if val >= i64::from(i32::max_value()) => pack_i64(buf, MSGPACK_MARKER_I32, val),
+        val if val >= i64::from(i32::max_value()) => pack_i64(buf, MSGPACK_MARKER_I64, val),
 
         // Negative values
         val if val >= -32 && val < 0 => {
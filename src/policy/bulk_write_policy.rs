@@ -0,0 +1,70 @@
+// Copyright 2015-2020 Aerospike, Inc.
+//
+// Portions may be licensed to Aerospike, Inc. under one or more contributor
+// license agreements.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use crate::policy::{BasePolicy, PolicyLike, WritePolicy};
+
+/// `BulkWritePolicy` encapsulates parameters for bulk write operations.
+pub struct BulkWritePolicy {
+    /// Write policy applied to every record written.
+    pub write_policy: WritePolicy,
+
+    /// Maximum number of write requests in flight across all cluster nodes. Once the limit is
+    /// reached, no further records are read from the input until one of the pending writes
+    /// completes. The number of concurrent writes is also limited by the size of the client's
+    /// thread pool. Default: 128.
+    pub max_in_flight: usize,
+
+    /// Maximum number of write requests in flight to a single cluster node. Once the limit is
+    /// reached for the node owning the next record, no further records are read from the input
+    /// until one of the pending writes to that node completes. Default: 16.
+    ///
+    /// The limit is approximate: each write is counted against the node that owns the record's
+    /// partition at the time the record is read from the input, or against a random node if the
+    /// owner is not known. A write that is retried on a different node, e.g. because the
+    /// partition has migrated or the retry strategy selects `Replica::Random`, is still counted
+    /// against the original node.
+    pub max_in_flight_per_node: usize,
+
+    /// Stop reading records from the input after the first failed write. Writes that are already
+    /// in flight will still be completed. Default: false.
+    pub abort_on_error: bool,
+}
+
+impl BulkWritePolicy {
+    /// Create a new bulk write policy instance with the specified write policy.
+    pub fn new(write_policy: WritePolicy) -> Self {
+        BulkWritePolicy {
+            write_policy,
+            ..BulkWritePolicy::default()
+        }
+    }
+}
+
+impl Default for BulkWritePolicy {
+    fn default() -> Self {
+        BulkWritePolicy {
+            write_policy: WritePolicy::default(),
+            max_in_flight: 128,
+            max_in_flight_per_node: 16,
+            abort_on_error: false,
+        }
+    }
+}
+
+impl PolicyLike for BulkWritePolicy {
+    fn base(&self) -> &BasePolicy {
+        &self.write_policy.base_policy
+    }
+}
@@ -18,6 +18,7 @@
 
 mod admin_policy;
 mod batch_policy;
+mod bulk_write_policy;
 mod client_policy;
 mod commit_level;
 mod concurrency;
@@ -28,11 +29,13 @@ mod priority;
 mod query_policy;
 mod read_policy;
 mod record_exists_action;
+mod retry_strategy;
 mod scan_policy;
 mod write_policy;
 
 pub use self::admin_policy::AdminPolicy;
 pub use self::batch_policy::BatchPolicy;
+pub use self::bulk_write_policy::BulkWritePolicy;
 pub use self::client_policy::ClientPolicy;
 pub use self::commit_level::CommitLevel;
 pub use self::concurrency::Concurrency;
@@ -43,10 +46,14 @@ pub use self::priority::Priority;
 pub use self::query_policy::QueryPolicy;
 pub use self::read_policy::ReadPolicy;
 pub use self::record_exists_action::RecordExistsAction;
+pub use self::retry_strategy::{
+    DefaultRetryStrategy, FailedAttempt, Replica, RetryDecision, RetryStrategy, MAX_RETRY_HISTORY,
+};
 pub use self::scan_policy::ScanPolicy;
 pub use self::write_policy::WritePolicy;
 
 use std::option::Option;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// Trait implemented by most policy types; policies that implement this trait typically encompass
@@ -75,6 +82,13 @@ pub trait Policy {
     /// Time to sleep between retries. Set to zero to skip sleep. Default: 500ms.
     fn sleep_between_retries(&self) -> Option<Duration>;
 
+    /// Custom strategy that decides whether, where and when a failed transaction is retried. If
+    /// not set, the transaction is retried as configured by `max_retries` and
+    /// `sleep_between_retries`.
+    fn retry_strategy(&self) -> Option<&dyn RetryStrategy> {
+        None
+    }
+
     /// How replicas should be consulted in read operations to provide the desired consistency
     /// guarantee.
     fn consistency_level(&self) -> &ConsistencyLevel;
@@ -114,6 +128,10 @@ where
     fn sleep_between_retries(&self) -> Option<Duration> {
         self.base().sleep_between_retries()
     }
+
+    fn retry_strategy(&self) -> Option<&dyn RetryStrategy> {
+        self.base().retry_strategy()
+    }
 }
 
 /// Common parameters shared by all policy types.
@@ -143,6 +161,12 @@ pub struct BasePolicy {
     /// SleepBetweenReplies determines duration to sleep between retries if a
     /// transaction fails and the timeout was not exceeded.  Enter zero to skip sleep.
     pub sleep_between_retries: Option<Duration>,
+
+    /// RetryStrategy replaces the fixed retry logic configured by `max_retries` and
+    /// `sleep_between_retries`. The strategy decides after each failed attempt whether to retry
+    /// the transaction, which node to send it to and how long to sleep before doing so.
+    /// Default: `None`.
+    pub retry_strategy: Option<Arc<dyn RetryStrategy>>,
 }
 
 impl Policy for BasePolicy {
@@ -172,4 +196,10 @@ impl Policy for BasePolicy {
     fn sleep_between_retries(&self) -> Option<Duration> {
         self.sleep_between_retries
     }
+
+    fn retry_strategy(&self) -> Option<&dyn RetryStrategy> {
+        self.retry_strategy
+            .as_ref()
+            .map(|strategy| strategy.as_ref())
+    }
 }
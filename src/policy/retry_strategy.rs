@@ -0,0 +1,235 @@
+// Copyright 2015-2020 Aerospike, Inc.
+//
+// Portions may be licensed to Aerospike, Inc. under one or more contributor
+// license agreements.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::cluster::Node;
+use crate::errors::Error;
+
+/// Maximum number of failed attempts passed to `RetryStrategy::next_attempt`. If a transaction
+/// fails more often, only the most recent attempts are kept.
+pub const MAX_RETRY_HISTORY: usize = 16;
+
+/// Determines which cluster node a transaction attempt is sent to.
+///
+/// The replica is only taken into account for single record transactions. Batch, scan and query
+/// commands are always sent to the node they were created for, regardless of the replica returned
+/// by the retry strategy.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Replica {
+    /// Send the transaction to the node owning the master copy of the record's partition. This is
+    /// the default.
+    Master,
+
+    /// Send the transaction to a random, active cluster node. The server will proxy the
+    /// transaction to the node owning the partition. Useful to route around a node that is
+    /// temporarily unreachable from the client. Nodes that failed earlier attempts of the same
+    /// transaction are avoided, unless no other active node is available.
+    Random,
+}
+
+impl Default for Replica {
+    fn default() -> Replica {
+        Replica::Master
+    }
+}
+
+/// Details of a single, failed transaction attempt.
+#[derive(Debug)]
+pub struct FailedAttempt {
+    /// Node the attempt was sent to, or `None` if no node could be determined.
+    pub node: Option<Arc<Node>>,
+
+    /// Replica selection used for the attempt.
+    pub replica: Replica,
+
+    /// Error returned by the attempt.
+    pub error: Error,
+
+    /// Whether the request had already been sent to the server when the error occurred, e.g.
+    /// because the connection failed while waiting for the response. For write transactions this
+    /// means the write may or may not have been applied. Errors returned by the server itself,
+    /// such as `ResultCode::KeyNotFoundError`, are definite results; they fail the transaction
+    /// immediately and are never passed to the retry strategy.
+    pub in_doubt: bool,
+}
+
+/// Decision returned by a `RetryStrategy` after a failed transaction attempt.
+#[derive(Debug, PartialEq, Clone)]
+pub enum RetryDecision {
+    /// Do not retry; the transaction fails with the error of the last attempt.
+    Abort,
+
+    /// Retry the transaction on the given replica, after sleeping for the optional delay.
+    Retry {
+        /// Time to sleep before the next attempt.
+        delay: Option<Duration>,
+
+        /// Node selection for the next attempt. Ignored by batch, scan and query commands; see
+        /// `Replica`.
+        replica: Replica,
+    },
+}
+
+/// Decides whether, where and when a failed transaction is retried.
+///
+/// The strategy is consulted after every failed attempt, with the number of attempts made so far
+/// for the current transaction and the history of failed attempts. The transaction timeout set
+/// on the policy is enforced independently of the strategy.
+///
+/// Attempts are strictly sequential: the next attempt is only made once the previous one has
+/// failed. Hedged requests, i.e. speculative attempts sent in parallel to several nodes, are not
+/// supported.
+///
+/// # Examples
+///
+/// Retry transactions that could not be sent up to three times, and fail over to a random node
+/// once the master node failed twice.
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use aerospike::*;
+///
+/// #[derive(Debug)]
+/// struct FailoverStrategy;
+///
+/// impl RetryStrategy for FailoverStrategy {
+///     fn next_attempt(&self, attempt: usize, history: &[FailedAttempt]) -> RetryDecision {
+///         let in_doubt = history.last().map_or(false, |failure| failure.in_doubt);
+///         if in_doubt || attempt > 3 {
+///             return RetryDecision::Abort;
+///         }
+///
+///         let master_failures = history
+///             .iter()
+///             .filter(|failure| failure.replica == Replica::Master)
+///             .count();
+///         let replica = if master_failures < 2 {
+///             Replica::Master
+///         } else {
+///             Replica::Random
+///         };
+///         RetryDecision::Retry {
+///             delay: None,
+///             replica,
+///         }
+///     }
+/// }
+///
+/// let mut policy = ReadPolicy::default();
+/// policy.retry_strategy = Some(Arc::new(FailoverStrategy));
+/// ```
+pub trait RetryStrategy: fmt::Debug + Send + Sync {
+    /// Decide how to proceed after a failed attempt. `attempt` is the number of attempts made so
+    /// far, including the failed one, starting at 1. `history` holds the failed attempts of the
+    /// current transaction, oldest first; the last entry is the attempt that just failed. At most
+    /// `MAX_RETRY_HISTORY` attempts are kept.
+    fn next_attempt(&self, attempt: usize, history: &[FailedAttempt]) -> RetryDecision;
+}
+
+/// Retry strategy used when no custom strategy is set on the policy. Attempts that failed before
+/// the request was sent are retried on the partition's master node up to `max_retries` times,
+/// sleeping `sleep_between_retries` between attempts. Attempts that are in doubt are never
+/// retried.
+#[derive(Debug, Clone)]
+pub struct DefaultRetryStrategy {
+    max_retries: Option<usize>,
+    sleep_between_retries: Option<Duration>,
+}
+
+impl DefaultRetryStrategy {
+    /// Create a new default retry strategy.
+    pub const fn new(max_retries: Option<usize>, sleep_between_retries: Option<Duration>) -> Self {
+        DefaultRetryStrategy {
+            max_retries,
+            sleep_between_retries,
+        }
+    }
+}
+
+impl RetryStrategy for DefaultRetryStrategy {
+    fn next_attempt(&self, attempt: usize, history: &[FailedAttempt]) -> RetryDecision {
+        if history.last().map_or(false, |failure| failure.in_doubt) {
+            return RetryDecision::Abort;
+        }
+
+        if let Some(max_retries) = self.max_retries {
+            if attempt > max_retries {
+                return RetryDecision::Abort;
+            }
+        }
+
+        RetryDecision::Retry {
+            delay: self.sleep_between_retries,
+            replica: Replica::Master,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DefaultRetryStrategy, FailedAttempt, Replica, RetryDecision, RetryStrategy};
+    use crate::errors::ErrorKind;
+
+    fn failure(in_doubt: bool) -> FailedAttempt {
+        FailedAttempt {
+            node: None,
+            replica: Replica::Master,
+            error: ErrorKind::Connection("test".to_string()).into(),
+            in_doubt,
+        }
+    }
+
+    #[test]
+    fn default_strategy_retries_up_to_max_retries() {
+        let sleep = Some(Duration::from_millis(10));
+        let strategy = DefaultRetryStrategy::new(Some(2), sleep);
+
+        for attempt in 1..=2 {
+            assert_eq!(
+                strategy.next_attempt(attempt, &[failure(false)]),
+                RetryDecision::Retry {
+                    delay: sleep,
+                    replica: Replica::Master,
+                }
+            );
+        }
+
+        assert_eq!(
+            strategy.next_attempt(3, &[failure(false)]),
+            RetryDecision::Abort
+        );
+    }
+
+    #[test]
+    fn default_strategy_does_not_retry_in_doubt() {
+        let strategy = DefaultRetryStrategy::new(None, None);
+        assert_eq!(
+            strategy.next_attempt(1, &[failure(false)]),
+            RetryDecision::Retry {
+                delay: None,
+                replica: Replica::Master,
+            }
+        );
+        assert_eq!(
+            strategy.next_attempt(2, &[failure(false), failure(true)]),
+            RetryDecision::Abort
+        );
+    }
+}
@@ -30,6 +30,7 @@ lazy_static! {
     static ref AEROSPIKE_NAMESPACE: String =
         env::var("AEROSPIKE_NAMESPACE").unwrap_or_else(|_| String::from("test"));
     static ref AEROSPIKE_CLUSTER: Option<String> = env::var("AEROSPIKE_CLUSTER").ok();
+    static ref AEROSPIKE_XDR_DC: Option<String> = env::var("AEROSPIKE_XDR_DC").ok();
     static ref GLOBAL_CLIENT_POLICY: ClientPolicy = {
         let mut policy = ClientPolicy::default();
         if let Ok(user) = env::var("AEROSPIKE_USER") {
@@ -51,6 +52,11 @@ pub fn namespace() -> &'static str {
     &*AEROSPIKE_NAMESPACE
 }
 
+// Name of an XDR datacenter configured for the test namespace, if any.
+pub fn xdr_datacenter() -> Option<&'static str> {
+    AEROSPIKE_XDR_DC.as_ref().map(String::as_str)
+}
+
 pub fn client_policy() -> &'static ClientPolicy {
     &*GLOBAL_CLIENT_POLICY
 }
@@ -0,0 +1,183 @@
+// Copyright 2015-2018 Aerospike, Inc.
+//
+// Portions may be licensed to Aerospike, Inc. under one or more contributor
+// license agreements.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use aerospike::policy::RecordExistsAction;
+use aerospike::{
+    as_bin, as_key, Bins, BulkWritePolicy, Error, ErrorKind, Key, ReadPolicy, ResultCode, Value,
+    WritePolicy,
+};
+
+use env_logger;
+
+use crate::common;
+
+// Writes records with keys `0..count` to the given set.
+fn write_records(set_name: &str, count: i64) {
+    let client = common::client();
+    let wpolicy = WritePolicy::default();
+    for i in 0..count {
+        let key = as_key!(common::namespace(), set_name, i);
+        client.put(&wpolicy, &key, &[as_bin!("i", i)]).unwrap();
+    }
+}
+
+fn is_key_exists_error(err: &Error) -> bool {
+    match *err {
+        Error(ErrorKind::ServerError(ResultCode::KeyExistsError), _) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn put_stream() {
+    let _ = env_logger::try_init();
+
+    let client = common::client();
+    let namespace: &str = common::namespace();
+    let set_name = &common::rand_str(10);
+    let mut policy = BulkWritePolicy::default();
+    policy.max_in_flight = 8;
+    policy.max_in_flight_per_node = 4;
+
+    let records = (0..100).map(|i| (as_key!(namespace, set_name, i), vec![as_bin!("i", i)]));
+    let summary = client.put_stream(&policy, records).unwrap();
+    assert!(summary.is_success());
+    assert_eq!(summary.written, 100);
+
+    let rpolicy = ReadPolicy::default();
+    for i in 0..100 {
+        let key = as_key!(namespace, set_name, i);
+        let record = client.get(&rpolicy, &key, Bins::All).unwrap();
+        assert_eq!(record.bins.get("i"), Some(&Value::from(i)));
+    }
+}
+
+#[test]
+fn put_stream_single_request_in_flight() {
+    let _ = env_logger::try_init();
+
+    let client = common::client();
+    let namespace: &str = common::namespace();
+    let set_name = &common::rand_str(10);
+    let mut policy = BulkWritePolicy::default();
+    policy.max_in_flight = 1;
+    policy.max_in_flight_per_node = 1;
+
+    let records = (0..20).map(|i| (as_key!(namespace, set_name, i), vec![as_bin!("i", i)]));
+    let summary = client.put_stream(&policy, records).unwrap();
+    assert!(summary.is_success());
+    assert_eq!(summary.written, 20);
+}
+
+#[test]
+fn put_stream_failure_summary() {
+    let _ = env_logger::try_init();
+
+    let client = common::client();
+    let namespace: &str = common::namespace();
+    let set_name = &common::rand_str(10);
+    write_records(set_name, 10);
+
+    let mut policy = BulkWritePolicy::default();
+    policy.write_policy.record_exists_action = RecordExistsAction::CreateOnly;
+
+    let records = (0..20).map(|i| (as_key!(namespace, set_name, i), vec![as_bin!("i", i)]));
+    let summary = client.put_stream(&policy, records).unwrap();
+    assert!(!summary.is_success());
+    assert_eq!(summary.written, 10);
+    assert_eq!(summary.failures.len(), 10);
+
+    let mut failed: Vec<Key> = vec![];
+    for (key, err) in summary.failures {
+        assert!(is_key_exists_error(&err), "Unexpected error: {}", err);
+        failed.push(key);
+    }
+    for i in 0..10 {
+        assert!(failed.contains(&as_key!(namespace, set_name, i)));
+    }
+}
+
+#[test]
+fn put_stream_abort_on_error() {
+    let _ = env_logger::try_init();
+
+    let client = common::client();
+    let namespace: &str = common::namespace();
+    let set_name = &common::rand_str(10);
+    write_records(set_name, 1);
+
+    let mut policy = BulkWritePolicy::default();
+    policy.write_policy.record_exists_action = RecordExistsAction::CreateOnly;
+    policy.max_in_flight = 1;
+    policy.abort_on_error = true;
+
+    let mut records = (0..20).map(|i| (as_key!(namespace, set_name, i), vec![as_bin!("i", i)]));
+    let summary = client.put_stream(&policy, records.by_ref()).unwrap();
+    assert!(summary.aborted);
+    assert_eq!(summary.written, 0);
+    assert_eq!(summary.failures.len(), 1);
+    assert!(is_key_exists_error(&summary.failures[0].1));
+
+    // no records after the failed one have been written
+    let rpolicy = ReadPolicy::default();
+    let key = as_key!(namespace, set_name, 1);
+    assert!(client.get(&rpolicy, &key, Bins::None).is_err());
+
+    // the next record is skipped if it was read while waiting for the failed write
+    let next = match summary.skipped.len() {
+        0 => 1,
+        1 => {
+            assert_eq!(summary.skipped[0], key);
+            2
+        }
+        n => panic!("Unexpected number of skipped records: {}", n),
+    };
+
+    // the remaining records can still be read from the input
+    let (key, _) = records.next().unwrap();
+    assert_eq!(key, as_key!(namespace, set_name, next));
+}
+
+#[test]
+fn put_stream_not_aborted() {
+    let _ = env_logger::try_init();
+
+    let client = common::client();
+    let namespace: &str = common::namespace();
+    let set_name = &common::rand_str(10);
+    let mut policy = BulkWritePolicy::default();
+    policy.abort_on_error = true;
+
+    let records = (0..20).map(|i| (as_key!(namespace, set_name, i), vec![as_bin!("i", i)]));
+    let summary = client.put_stream(&policy, records).unwrap();
+    assert!(!summary.aborted);
+    assert!(summary.skipped.is_empty());
+    assert_eq!(summary.written, 20);
+}
+
+#[test]
+fn put_stream_invalid_policy() {
+    let _ = env_logger::try_init();
+
+    let client = common::client();
+    let mut policy = BulkWritePolicy::default();
+    policy.max_in_flight = 0;
+
+    let records = vec![(
+        as_key!(common::namespace(), "test", 1),
+        vec![as_bin!("i", 1)],
+    )];
+    assert!(client.put_stream(&policy, records).is_err());
+}
@@ -100,3 +100,20 @@ fn connect() {
     let existed = client.delete(&wpolicy, &key).unwrap();
     assert!(!existed);
 }
+
+#[test]
+fn node_for_key() {
+    let _ = env_logger::try_init();
+
+    let client = common::client();
+    let namespace: &str = common::namespace();
+    let set_name = &common::rand_str(10);
+    let key = as_key!(namespace, set_name, 1);
+
+    assert!(key.partition_id() < 4096);
+    let node = client.node_for_key(&key).unwrap();
+    assert!(client.node_names().contains(&node.name().to_owned()));
+
+    let key = as_key!("no-such-namespace", set_name, 1);
+    assert!(client.node_for_key(&key).is_err());
+}
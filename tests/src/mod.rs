@@ -14,12 +14,15 @@
 // the License.
 
 mod batch;
+mod bulk_write;
 mod cdt_list;
 mod cdt_map;
 mod index;
 mod kv;
 mod query;
+mod retry;
 mod scan;
 mod task;
 mod truncate;
 mod udf;
+mod xdr;
@@ -0,0 +1,81 @@
+// Copyright 2015-2018 Aerospike, Inc.
+//
+// Portions may be licensed to Aerospike, Inc. under one or more contributor
+// license agreements.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use aerospike::{
+    as_bin, as_key, Bins, Error, ErrorKind, FailedAttempt, ReadPolicy, ResultCode, RetryDecision,
+    RetryStrategy, WritePolicy,
+};
+
+use env_logger;
+
+use crate::common;
+
+// Counts how often it is consulted; never retries.
+#[derive(Debug, Default)]
+struct CountingStrategy {
+    calls: AtomicUsize,
+}
+
+impl RetryStrategy for CountingStrategy {
+    fn next_attempt(&self, _attempt: usize, _history: &[FailedAttempt]) -> RetryDecision {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        RetryDecision::Abort
+    }
+}
+
+#[test]
+fn retry_strategy_not_called_on_success() {
+    let _ = env_logger::try_init();
+
+    let client = common::client();
+    let namespace: &str = common::namespace();
+    let set_name = &common::rand_str(10);
+    let strategy = Arc::new(CountingStrategy::default());
+    let dyn_strategy: Arc<dyn RetryStrategy> = strategy.clone();
+    let mut wpolicy = WritePolicy::default();
+    wpolicy.base_policy.retry_strategy = Some(dyn_strategy.clone());
+    let mut rpolicy = ReadPolicy::default();
+    rpolicy.retry_strategy = Some(dyn_strategy);
+
+    let key = as_key!(namespace, set_name, 1);
+    client.put(&wpolicy, &key, &[as_bin!("i", 1)]).unwrap();
+    client.get(&rpolicy, &key, Bins::All).unwrap();
+
+    assert_eq!(strategy.calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn retry_strategy_not_called_on_server_error() {
+    let _ = env_logger::try_init();
+
+    let client = common::client();
+    let namespace: &str = common::namespace();
+    let set_name = &common::rand_str(10);
+    let strategy = Arc::new(CountingStrategy::default());
+    let dyn_strategy: Arc<dyn RetryStrategy> = strategy.clone();
+    let mut rpolicy = ReadPolicy::default();
+    rpolicy.retry_strategy = Some(dyn_strategy);
+
+    let key = as_key!(namespace, set_name, -1);
+    match client.get(&rpolicy, &key, Bins::All) {
+        Err(Error(ErrorKind::ServerError(ResultCode::KeyNotFoundError), _)) => {}
+        _ => panic!("Expected KeyNotFoundError"),
+    }
+
+    assert_eq!(strategy.calls.load(Ordering::SeqCst), 0);
+}
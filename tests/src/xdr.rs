@@ -0,0 +1,89 @@
+// Copyright 2015-2020 Aerospike, Inc.
+//
+// Portions may be licensed to Aerospike, Inc. under one or more contributor
+// license agreements.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use aerospike::expressions::{and, eq, ge, int_val, string_bin, string_val, ttl};
+use aerospike::{Error, ErrorKind, WritePolicy};
+
+use env_logger;
+
+use crate::common;
+
+fn is_invalid_argument<T>(result: Result<T, Error>) -> bool {
+    match result {
+        Err(Error(ErrorKind::InvalidArgument(_), _)) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn xdr_filter() {
+    let _ = env_logger::try_init();
+
+    // requires XDR shipping of the test namespace to the datacenter set in AEROSPIKE_XDR_DC
+    let datacenter = match common::xdr_datacenter() {
+        Some(datacenter) => datacenter,
+        None => return,
+    };
+
+    let client = common::client();
+    let namespace: &str = common::namespace();
+    let policy = WritePolicy::default();
+    let filter = and(vec![
+        eq(string_bin("region"), string_val("eu")),
+        ge(ttl(), int_val(3600)),
+    ]);
+
+    client
+        .set_xdr_filter(&policy, datacenter, namespace, &filter)
+        .unwrap();
+    let current = client
+        .get_xdr_filter(&policy, datacenter, namespace)
+        .unwrap()
+        .expect("XDR filter not set");
+    assert_eq!(current.to_base64().unwrap(), filter.to_base64().unwrap());
+
+    client
+        .clear_xdr_filter(&policy, datacenter, namespace)
+        .unwrap();
+    let current = client
+        .get_xdr_filter(&policy, datacenter, namespace)
+        .unwrap();
+    assert!(current.is_none());
+}
+
+#[test]
+fn xdr_filter_invalid_args() {
+    let _ = env_logger::try_init();
+
+    let client = common::client();
+    let namespace: &str = common::namespace();
+    let policy = WritePolicy::default();
+    let filter = eq(string_bin("region"), string_val("eu"));
+
+    assert!(is_invalid_argument(client.set_xdr_filter(
+        &policy,
+        "dc1;namespace=other",
+        namespace,
+        &filter
+    )));
+    assert!(is_invalid_argument(
+        client.get_xdr_filter(&policy, "", namespace)
+    ));
+    assert!(is_invalid_argument(client.clear_xdr_filter(
+        &policy,
+        "dc1",
+        "test:exp=null"
+    )));
+}